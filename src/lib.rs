@@ -5,6 +5,9 @@ use serde::{Deserialize, Deserializer};
 use std::fs;
 use std::io;
 use std::process::Command;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
 use url::Url;
 
 #[derive(Deserialize)]
@@ -15,6 +18,12 @@ struct Config {
     op_path: String,
     #[serde(default = "prefix")]
     prefix: String,
+    #[serde(default = "lock_timeout")]
+    lock_timeout: u64,
+    #[serde(default)]
+    account: Option<String>,
+    #[serde(default)]
+    vaults: Vec<String>,
 }
 
 fn max_entries() -> usize {
@@ -29,12 +38,19 @@ fn prefix() -> String {
     "".into()
 }
 
+fn lock_timeout() -> u64 {
+    3600
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
             max_entries: max_entries(),
             op_path: op_path(),
             prefix: prefix(),
+            lock_timeout: lock_timeout(),
+            account: None,
+            vaults: Vec::new(),
         }
     }
 }
@@ -46,6 +62,13 @@ struct OpListItem {
     category: String,
     #[serde(default)]
     urls: Vec<OpUrl>,
+    #[serde(default)]
+    vault: Option<OpVault>,
+}
+
+#[derive(Deserialize, Debug)]
+struct OpVault {
+    name: String,
 }
 
 #[derive(Deserialize, Debug)]
@@ -64,9 +87,33 @@ struct OpField {
     id: String,
     #[serde(alias = "type")]
     tpe: String,
+    label: Option<String>,
     value: Option<String>,
 }
 
+enum Needle {
+    Name(String),
+    Uri(Url),
+    Id(String),
+}
+
+/// 1Password item ids are 26-character lowercase alphanumeric strings (e.g.
+/// `wxcplm4vp4bq7acf7oc6mdx6ci`), not RFC-4122 UUIDs, so recognise them by
+/// their shape rather than trying to parse a UUID.
+fn is_op_id(input: &str) -> bool {
+    input.len() == 26 && input.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+fn parse_needle(input: &str) -> Needle {
+    if is_op_id(input) {
+        Needle::Id(input.to_string())
+    } else if let Ok(url) = Url::parse(input) {
+        Needle::Uri(url)
+    } else {
+        Needle::Name(input.to_string())
+    }
+}
+
 fn host_from_url<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
 where
     D: Deserializer<'de>,
@@ -81,75 +128,316 @@ where
 #[derive(Debug)]
 enum Error {
     OpCommandFailed(io::Error),
-    OpReturnCodeError(i32),
+    OpReturnCodeError { code: i32, stderr: String },
     ReadOutputError(std::string::FromUtf8Error),
     ParsingError(serde_json::Error),
 }
 
 struct State {
     config: Config,
+    config_path: String,
+    config_mtime: Option<SystemTime>,
     items: Vec<(u64, OpListItem)>,
     input: Option<String>,
     selection: Option<Selection>,
+    session: Option<String>,
+    last_access: Instant,
+    last_fetch: Instant,
+    pending_refresh: Option<Receiver<Vec<OpListItem>>>,
 }
 
 struct Selection {
     id: String,
-    username: Option<String>,
-    password: Option<String>,
-    has_otp: bool,
-    ccnum: Option<String>,
-    cvv: Option<String>,
-    expiry: Option<String>,
+    vault: Option<String>,
+    fields: Vec<SelectionField>,
+}
+
+#[derive(Debug)]
+struct SelectionField {
+    label: String,
+    field_id: String,
+    tpe: String,
+    value: Option<String>,
+    is_otp: bool,
 }
 
-fn execute_command(cmd: &str, args: &[&str]) -> Result<String, Error> {
-    let output = Command::new(cmd)
-        .args(args)
-        .output()
-        .map_err(Error::OpCommandFailed);
+fn execute_command(cmd: &str, args: &[&str], session: Option<&str>) -> Result<String, Error> {
+    let mut command = Command::new(cmd);
+    command.args(args);
+    if let Some(token) = session {
+        command.arg("--session").arg(token);
+    }
+
+    let output = command.output().map_err(Error::OpCommandFailed);
 
     output.and_then(|o| {
         if o.status.success() {
             String::from_utf8(o.stdout).map_err(Error::ReadOutputError)
         } else {
-            Err(Error::OpReturnCodeError(o.status.code().unwrap()))
+            Err(Error::OpReturnCodeError {
+                code: o.status.code().unwrap(),
+                stderr: String::from_utf8_lossy(&o.stderr).into_owned(),
+            })
         }
     })
 }
 
 const ITEM_LIST_ARGS: [&str; 3] = ["item", "list", "--format=json"];
 
-#[init]
-fn init(config_dir: RString) -> State {
-    let config: Config = load_config(config_dir);
+/// Substrings `op` prints to stderr when the session is missing or expired.
+/// Matching these (rather than the generic exit code 1) keeps benign failures
+/// — network blips, deleted items — from triggering an interactive re-unlock.
+const SESSION_EXPIRED_SIGNALS: [&str; 3] = [
+    "you are not currently signed in",
+    "session expired",
+    "session is expired",
+];
+
+/// How long a loaded item list is served before it is re-fetched in the
+/// background so long-running anyrun sessions don't keep serving stale data.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Whether an `op` stderr message indicates the cached session is no longer
+/// valid and a fresh `op signin` is warranted.
+fn is_session_expired(stderr: &str) -> bool {
+    let stderr = stderr.to_lowercase();
+    SESSION_EXPIRED_SIGNALS
+        .iter()
+        .any(|signal| stderr.contains(signal))
+}
 
-    let content = match execute_command(&config.op_path, &ITEM_LIST_ARGS) {
-        Err(Error::OpReturnCodeError(_)) => execute_command(&config.op_path, &ITEM_LIST_ARGS),
-        other => other,
+/// Fetch the (optionally account/vault-scoped) item list using the cached
+/// session only. Used by the background refresh path (the synchronous path
+/// goes through `State::fetch_items`); returns `None` when every `op` call or
+/// parse fails.
+fn fetch_item_list(
+    op_path: &str,
+    session: Option<&str>,
+    account: Option<&str>,
+    vaults: &[String],
+) -> Option<Vec<OpListItem>> {
+    let fetch_one = |vault: Option<&str>| {
+        let mut args: Vec<&str> = ITEM_LIST_ARGS.to_vec();
+        if let Some(account) = account {
+            args.push("--account");
+            args.push(account);
+        }
+        if let Some(vault) = vault {
+            args.push("--vault");
+            args.push(vault);
+        }
+
+        execute_command(op_path, &args, session)
+            .and_then(|s| {
+                serde_json::from_str::<Vec<OpListItem>>(s.as_str()).map_err(Error::ParsingError)
+            })
+            .ok()
     };
 
-    let op_items = content
-        .and_then(|s| {
-            serde_json::from_str::<Vec<OpListItem>>(s.as_str()).map_err(Error::ParsingError)
-        })
-        .map(|items| {
-            items
-                .into_iter()
-                .filter(|i| i.category == "PASSWORD" || i.category == "LOGIN" || i.category == "CREDIT_CARD")
-                .enumerate()
-                .map(|(id, item)| (id as u64, item))
-                .collect::<Vec<_>>()
+    if vaults.is_empty() {
+        return fetch_one(None);
+    }
+
+    let mut collected = Vec::new();
+    let mut fetched = false;
+    for vault in vaults {
+        if let Some(items) = fetch_one(Some(vault)) {
+            collected.extend(items);
+            fetched = true;
+        }
+    }
+    fetched.then_some(collected)
+}
+
+impl State {
+    /// Run `op`, transparently re-unlocking once if the cached session has
+    /// expired. Every call counts as activity and postpones the auto-lock.
+    fn run_op(&mut self, args: &[&str]) -> Result<String, Error> {
+        self.last_access = Instant::now();
+
+        match execute_command(&self.config.op_path, args, self.session.as_deref()) {
+            Err(Error::OpReturnCodeError { stderr, .. }) if is_session_expired(&stderr) => {
+                let token = self.signin()?;
+                let result = execute_command(&self.config.op_path, args, Some(&token));
+                self.session = Some(token);
+                result
+            }
+            other => other,
+        }
+    }
+
+    /// Acquire a fresh session token with `op signin --raw`.
+    fn signin(&self) -> Result<String, Error> {
+        execute_command(&self.config.op_path, &["signin", "--raw"], None)
+            .map(|s| s.trim().to_string())
+    }
+
+    /// (Re-)load the item list, replacing the cached entries on success and
+    /// leaving them untouched on failure. When `vaults` is set the list is
+    /// fetched once per vault and the results are concatenated.
+    fn refresh_items(&mut self) {
+        let account = self.config.account.clone();
+        let vaults = self.config.vaults.clone();
+
+        let mut collected: Vec<OpListItem> = Vec::new();
+        let mut fetched = false;
+
+        if vaults.is_empty() {
+            if let Some(items) = self.fetch_items(account.as_deref(), None) {
+                collected = items;
+                fetched = true;
+            }
+        } else {
+            for vault in &vaults {
+                if let Some(items) = self.fetch_items(account.as_deref(), Some(vault)) {
+                    collected.extend(items);
+                    fetched = true;
+                }
+            }
+        }
+
+        if fetched {
+            self.set_items(collected);
+        }
+    }
+
+    /// Fetch the item list for a single account/vault scope, returning `None`
+    /// when the `op` call or its parse fails. Goes through [`run_op`] so an
+    /// expired session is renewed before giving up.
+    fn fetch_items(&mut self, account: Option<&str>, vault: Option<&str>) -> Option<Vec<OpListItem>> {
+        let mut args: Vec<&str> = ITEM_LIST_ARGS.to_vec();
+        if let Some(account) = account {
+            args.push("--account");
+            args.push(account);
+        }
+        if let Some(vault) = vault {
+            args.push("--vault");
+            args.push(vault);
+        }
+
+        self.run_op(&args)
+            .and_then(|s| {
+                serde_json::from_str::<Vec<OpListItem>>(s.as_str()).map_err(Error::ParsingError)
+            })
+            .ok()
+    }
+
+    /// Replace the cached entries, re-numbering them and stamping the fetch time.
+    fn set_items(&mut self, items: Vec<OpListItem>) {
+        self.items = items
+            .into_iter()
+            .enumerate()
+            .map(|(id, item)| (id as u64, item))
+            .collect();
+        self.last_fetch = Instant::now();
+    }
+
+    /// Kick off a non-blocking item-list re-fetch on a worker thread. The
+    /// result is picked up by [`refresh_if_stale`] on a later call so the match
+    /// path never waits on `op`. Uses the cached session only — it never opens
+    /// an interactive signin from the background.
+    fn spawn_background_refresh(&mut self) {
+        let op_path = self.config.op_path.clone();
+        let session = self.session.clone();
+        let account = self.config.account.clone();
+        let vaults = self.config.vaults.clone();
+
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            if let Some(items) =
+                fetch_item_list(&op_path, session.as_deref(), account.as_deref(), &vaults)
+            {
+                let _ = tx.send(items);
+            }
         });
 
-    op_items
-        .map(|items| State {
-            config,
-            items,
-            input: None,
-            selection: None,
-        })
-        .unwrap()
+        self.pending_refresh = Some(rx);
+        self.last_fetch = Instant::now();
+    }
+
+    /// Drop the cached session and decrypted credentials once the plugin has
+    /// been idle for longer than `lock_timeout` (disabled when zero).
+    fn enforce_lock_timeout(&mut self) {
+        let timeout = self.config.lock_timeout;
+        if timeout > 0 && self.last_access.elapsed().as_secs() >= timeout {
+            self.session = None;
+            self.items.clear();
+            self.pending_refresh = None;
+            self.selection = None;
+            self.input = None;
+        }
+        self.last_access = Instant::now();
+    }
+
+    /// Adopt a finished background refresh if one is ready, then decide whether
+    /// another fetch is needed. A missing list (e.g. just locked) is fetched
+    /// synchronously because there is nothing to serve; a merely stale list is
+    /// refreshed on a worker thread so the match path never blocks on `op`.
+    fn refresh_if_stale(&mut self) {
+        match self.pending_refresh.as_ref().map(Receiver::try_recv) {
+            Some(Ok(items)) => {
+                self.set_items(items);
+                self.pending_refresh = None;
+            }
+            Some(Err(TryRecvError::Disconnected)) => self.pending_refresh = None,
+            _ => {}
+        }
+
+        if self.items.is_empty() {
+            self.refresh_items();
+        } else if self.pending_refresh.is_none() && self.last_fetch.elapsed() >= REFRESH_INTERVAL {
+            self.spawn_background_refresh();
+        }
+    }
+
+    /// Re-read `op.ron` when its mtime changes and swap in the new config so
+    /// `prefix`, `max_entries` or `op_path` can be tuned without restarting
+    /// anyrun. A changed `op_path` also drops the cached items so they are
+    /// re-fetched through the new binary.
+    fn reload_config_if_changed(&mut self) {
+        let mtime = fs::metadata(&self.config_path)
+            .and_then(|m| m.modified())
+            .ok();
+
+        if mtime == self.config_mtime {
+            return;
+        }
+        self.config_mtime = mtime;
+
+        let config = read_config(&self.config_path);
+        let op_path_changed = config.op_path != self.config.op_path;
+        self.config = config;
+
+        if op_path_changed {
+            self.items.clear();
+        }
+    }
+}
+
+#[init]
+fn init(config_dir: RString) -> State {
+    let config_path = format!("{}/op.ron", config_dir);
+    let config_mtime = fs::metadata(&config_path)
+        .and_then(|m| m.modified())
+        .ok();
+    let config = read_config(&config_path);
+
+    let now = Instant::now();
+    let mut state = State {
+        config,
+        config_path,
+        config_mtime,
+        items: Vec::new(),
+        input: None,
+        selection: None,
+        session: None,
+        last_access: now,
+        last_fetch: now,
+        pending_refresh: None,
+    };
+
+    state.refresh_items();
+    state
 }
 
 #[info]
@@ -160,8 +448,8 @@ fn info() -> PluginInfo {
     }
 }
 
-fn load_config(config_dir: RString) -> Config {
-    match fs::read_to_string(format!("{}/op.ron", config_dir)) {
+fn read_config(path: &str) -> Config {
+    match fs::read_to_string(path) {
         Ok(content) => ron::from_str(&content).unwrap_or_else(|why| {
             eprintln!("Error parsing op plugin config: {}", why);
             Config::default()
@@ -175,6 +463,10 @@ fn load_config(config_dir: RString) -> Config {
 
 #[get_matches]
 fn get_matches(input: RString, state: &mut State) -> RVec<Match> {
+    state.reload_config_if_changed();
+    state.enforce_lock_timeout();
+    state.refresh_if_stale();
+
     match &state.selection {
         None => display_matching_items(&input, state),
         Some(selection) => match &state.input {
@@ -198,199 +490,186 @@ fn display_matching_items(input: &RString, state: &mut State) -> RVec<Match> {
     }
 
     let cleaned_input = &input[state.config.prefix.len()..];
-    let matcher = fuzzy_matcher::skim::SkimMatcherV2::default().smart_case();
+    state.input = Some(input.to_string());
 
-    let mut entries = state
-        .items
-        .iter()
-        .filter_map(|(id, e)| {
-            let title_score = matcher.fuzzy_match(&e.title, cleaned_input).unwrap_or(0);
-            let domain_score = e
-                .urls
+    let entries: Vec<(&u64, &OpListItem)> = match parse_needle(cleaned_input) {
+        Needle::Id(needle) => state
+            .items
+            .iter()
+            .filter(|(_, e)| e.id == needle)
+            .map(|(id, e)| (id, e))
+            .collect(),
+        Needle::Uri(url) => {
+            let host = url.host_str().map(|h| h.to_string());
+            state
+                .items
                 .iter()
-                .flat_map(|u| u.href.clone())
-                .map(|domain| matcher.fuzzy_match(&domain, cleaned_input).unwrap_or(0))
-                .max()
-                .unwrap_or(0);
-            let score = std::cmp::max(title_score, domain_score);
-            if score > 0 {
-                Some((id, e, score))
-            } else {
-                None
-            }
-        })
-        .collect::<Vec<_>>();
+                .filter(|(_, e)| {
+                    host.as_ref().is_some_and(|h| {
+                        e.urls
+                            .iter()
+                            .flat_map(|u| u.href.as_ref())
+                            .any(|href| href == h)
+                    })
+                })
+                .map(|(id, e)| (id, e))
+                .collect()
+        }
+        Needle::Name(name) => {
+            let matcher = fuzzy_matcher::skim::SkimMatcherV2::default().smart_case();
 
-    entries.sort_by(|a, b| b.2.cmp(&a.2));
-    entries.truncate(state.config.max_entries);
+            let mut entries = state
+                .items
+                .iter()
+                .filter_map(|(id, e)| {
+                    let title_score = matcher.fuzzy_match(&e.title, &name).unwrap_or(0);
+                    let domain_score = e
+                        .urls
+                        .iter()
+                        .flat_map(|u| u.href.clone())
+                        .map(|domain| matcher.fuzzy_match(&domain, &name).unwrap_or(0))
+                        .max()
+                        .unwrap_or(0);
+                    let score = std::cmp::max(title_score, domain_score);
+                    if score > 0 {
+                        Some((id, e, score))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<_>>();
 
-    state.input = Some(input.to_string());
+            entries.sort_by(|a, b| b.2.cmp(&a.2));
+            entries.into_iter().map(|(id, e, _)| (id, e)).collect()
+        }
+    };
 
     entries
         .into_iter()
-        .map(|(id, e, _)| Match {
+        .take(state.config.max_entries)
+        .map(|(id, e)| Match {
             title: e.title.clone().into(),
             icon: ROption::RNone,
             use_pango: false,
-            description: ROption::RNone,
-            id: ROption::RSome(*id as u64),
+            description: scope_label(&state.config, e),
+            id: ROption::RSome(*id),
         })
         .collect()
 }
 
+/// Build the `account/vault` annotation shown on a match so identically named
+/// logins from different accounts or vaults stay distinguishable.
+fn scope_label(config: &Config, item: &OpListItem) -> ROption<RString> {
+    let mut parts = Vec::new();
+    if let Some(account) = &config.account {
+        parts.push(account.clone());
+    }
+    if let Some(vault) = &item.vault {
+        parts.push(vault.name.clone());
+    }
+
+    if parts.is_empty() {
+        ROption::RNone
+    } else {
+        ROption::RSome(parts.join("/").into())
+    }
+}
+
 fn display_selection_items(selection: &Selection) -> RVec<Match> {
-    let username = selection.username.as_ref().map(|_| Match {
-        title: "Username".into(),
-        icon: ROption::RNone,
-        use_pango: false,
-        description: ROption::RNone,
-        id: ROption::RSome(0),
-    });
-
-    let password = selection.password.as_ref().map(|_| Match {
-        title: "Password".into(),
-        icon: ROption::RNone,
-        use_pango: false,
-        description: ROption::RNone,
-        id: ROption::RSome(1),
-    });
-
-    let otp = if selection.has_otp {
-        Some(Match {
-            title: "One-time password".into(),
+    selection
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| f.is_otp || f.value.is_some())
+        .map(|(id, f)| Match {
+            title: f.label.clone().into(),
             icon: ROption::RNone,
             use_pango: false,
-            description: ROption::RNone,
-            id: ROption::RSome(2),
+            description: ROption::RSome(f.tpe.clone().into()),
+            id: ROption::RSome(id as u64),
         })
-    } else {
-        None
-    };
-
-    let ccnum = selection.ccnum.as_ref().map(|_| Match {
-        title: "Number".into(),
-        icon: ROption::RNone,
-        use_pango: false,
-        description: ROption::RNone,
-        id: ROption::RSome(3),
-    });
-
-    let ccv = selection.cvv.as_ref().map(|_| Match {
-        title: "CCV".into(),
-        icon: ROption::RNone,
-        use_pango: false,
-        description: ROption::RNone,
-        id: ROption::RSome(4),
-    });
-
-    let expiry = selection.expiry.as_ref().map(|_| Match {
-        title: "Expiry".into(),
-        icon: ROption::RNone,
-        use_pango: false,
-        description: ROption::RNone,
-        id: ROption::RSome(5),
-    });
-
-    vec![username, password, otp, ccnum, ccv, expiry]
-        .into_iter()
-        .flatten()
-        .collect::<Vec<_>>()
-        .into()
+        .collect()
 }
 
 #[handler]
 fn handler(selection: Match, state: &mut State) -> HandleResult {
     match &state.selection {
         None => {
-            let id = state
+            let (id, vault) = state
                 .items
                 .iter()
                 .find_map(|(id, item)| {
                     if *id == selection.id.unwrap() {
-                        Some(item.id.clone())
+                        Some((item.id.clone(), item.vault.as_ref().map(|v| v.name.clone())))
                     } else {
                         None
                     }
                 })
                 .unwrap();
 
-            let selected_item = execute_command(
-                &state.config.op_path,
-                &["items", "get", id.as_str(), "--format=json"],
-            )
-            .and_then(|s| {
-                serde_json::from_str::<OpGetItem>(s.as_str()).map_err(Error::ParsingError)
-            })
-            .unwrap();
-
-            let username = selected_item.fields.iter().find_map(|f| {
-                if f.id == "username" {
-                    f.value.clone()
-                } else {
-                    None
-                }
-            });
-
-            let password = selected_item.fields.iter().find_map(|f| {
-                if f.id == "password" {
-                    f.value.clone()
-                } else {
-                    None
-                }
-            });
-
-            let has_otp = selected_item.fields.iter().any(|f| f.tpe == "OTP");
+            let account = state.config.account.clone();
+            let mut args: Vec<&str> = vec!["items", "get", id.as_str(), "--format=json"];
+            if let Some(account) = &account {
+                args.push("--account");
+                args.push(account);
+            }
+            if let Some(vault) = &vault {
+                args.push("--vault");
+                args.push(vault);
+            }
 
-            let ccnum = selected_item.fields.iter().find_map(|f| {
-                if f.id == "ccnum" {
-                    f.value.clone()
-                } else {
-                    None
-                }
-            });
+            let selected_item = match state.run_op(&args).and_then(|s| {
+                serde_json::from_str::<OpGetItem>(s.as_str()).map_err(Error::ParsingError)
+            }) {
+                Ok(item) => item,
+                Err(_) => return HandleResult::Close,
+            };
 
-            let cvv = selected_item.fields.iter().find_map(|f| {
-                if f.id == "cvv" {
-                    f.value.clone()
-                } else {
-                    None
-                }
-            });
+            let fields = selected_item
+                .fields
+                .into_iter()
+                .map(|f| {
+                    let is_otp = f.tpe == "OTP";
+                    SelectionField {
+                        label: f.label.unwrap_or_else(|| f.id.clone()),
+                        field_id: f.id,
+                        tpe: f.tpe,
+                        value: f.value,
+                        is_otp,
+                    }
+                })
+                .collect();
 
-            let expiry = selected_item.fields.iter().find_map(|f| {
-                if f.id == "expiry" {
-                    f.value.clone()
-                } else {
-                    None
-                }
-            });
-
-            state.selection = Some(Selection {
-                id,
-                username,
-                password,
-                has_otp,
-                ccnum,
-                cvv,
-                expiry,
-            });
+            state.selection = Some(Selection { id, vault, fields });
 
             HandleResult::Refresh(true)
         }
 
         Some(s) => match selection.id {
-            ROption::RSome(0) => HandleResult::Copy(s.username.as_ref().unwrap().as_bytes().into()),
-            ROption::RSome(1) => HandleResult::Copy(s.password.as_ref().unwrap().as_bytes().into()),
-            ROption::RSome(2) => execute_command(
-                &state.config.op_path,
-                &["items", "get", s.id.as_str(), "--otp"],
-            )
-            .map(|otp| HandleResult::Copy(otp.trim().as_bytes().into()))
-            .unwrap(),
-            ROption::RSome(3) => HandleResult::Copy(s.ccnum.as_ref().unwrap().as_bytes().into()),
-            ROption::RSome(4) => HandleResult::Copy(s.cvv.as_ref().unwrap().as_bytes().into()),
-            ROption::RSome(5) => HandleResult::Copy(s.expiry.as_ref().unwrap().as_bytes().into()),
-            _ => HandleResult::Close,
+            ROption::RSome(id) => {
+                let field = &s.fields[id as usize];
+                if field.is_otp {
+                    let item_id = s.id.clone();
+                    let vault = s.vault.clone();
+                    let account = state.config.account.clone();
+                    let mut args: Vec<&str> = vec!["items", "get", item_id.as_str(), "--otp"];
+                    if let Some(account) = &account {
+                        args.push("--account");
+                        args.push(account);
+                    }
+                    if let Some(vault) = &vault {
+                        args.push("--vault");
+                        args.push(vault);
+                    }
+                    match state.run_op(&args) {
+                        Ok(otp) => HandleResult::Copy(otp.trim().as_bytes().into()),
+                        Err(_) => HandleResult::Close,
+                    }
+                } else {
+                    HandleResult::Copy(field.value.as_ref().unwrap().as_bytes().into())
+                }
+            }
+            ROption::RNone => HandleResult::Close,
         },
     }
 }